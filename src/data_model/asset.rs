@@ -1,19 +1,178 @@
+use std::str::FromStr;
+
 use rust_decimal::{prelude::FromPrimitive, Decimal};
 
-use iroha_data_model::asset::{
-    Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetType, AssetValue, Mintable,
-    NewAssetDefinition,
+use iroha_data_model::{
+    account::AccountId,
+    asset::{
+        Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetType, AssetValue, Mintable,
+        NewAssetDefinition,
+    },
+    domain::DomainId,
+    metadata::Metadata,
+    Name,
+};
+use iroha_primitives::{
+    json::Json,
+    numeric::{Numeric, NumericSpec},
 };
-use iroha_primitives::numeric::{Numeric, NumericSpec};
 
 use pyo3::{
-    exceptions::{PyNotImplementedError, PyValueError},
     prelude::*,
-    types::PyDict,
+    types::{PyBool, PyDict, PyList},
 };
 
-use super::account::PyAccountId;
-use crate::{data_model::PyMirror, mirror_fieldless_enum, mirror_struct};
+use super::{account::PyAccountId, domain::PyDomainId};
+use crate::{
+    data_model::PyMirror,
+    error::{AssetValueError, IpfsPathError, NameParseError},
+    mirror_fieldless_enum, mirror_struct,
+};
+
+/// Accepts either the mirror pyclass `M` (wrapping the real data-model
+/// type `T`) or a `&str` in `T`'s canonical parsed form. Centralizes the
+/// "extract-or-parse" pattern behind this module's flexible constructors
+/// and setters, e.g. letting `AssetId("rose#wonderland", "alice@wonderland")`
+/// work just as well as `AssetId(AssetDefinitionId(...), AccountId(...))`.
+fn extract_or_parse<'py, M, T>(value: &'py PyAny, what: &str) -> PyResult<T>
+where
+    M: FromPyObject<'py> + Into<T>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    if let Ok(mirror) = value.extract::<M>() {
+        Ok(mirror.into())
+    } else if let Ok(s) = value.extract::<&str>() {
+        s.parse()
+            .map_err(|e| NameParseError::new_err(format!("Invalid {what}: {e}")))
+    } else {
+        Err(NameParseError::new_err(format!(
+            "Invalid {what}, expected a {what} or a string"
+        )))
+    }
+}
+
+mirror_struct! {
+    /// Key-value metadata, e.g. attached to an asset definition
+    /// or stored as the value of a `Store` asset.
+    ///
+    /// Mirrors a Python `dict` with string keys; values may be
+    /// `int`, `float`, `str`, `bool`, `list` or `dict`, nested arbitrarily.
+    Metadata
+}
+
+#[pymethods]
+impl PyMetadata {
+    #[new]
+    fn new(py: Python<'_>, dict: &PyDict) -> PyResult<Self> {
+        Ok(Self(metadata_from_dict(py, dict)?))
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        metadata_to_dict(py, &self.0)
+    }
+}
+
+fn json_from_py(py: Python<'_>, value: &PyAny) -> PyResult<Json> {
+    if value.downcast::<PyBool>().is_ok() {
+        Ok(Json::new(value.extract::<bool>()?))
+    } else if let Ok(v) = value.extract::<i128>() {
+        Ok(Json::new(v))
+    } else if let Ok(v) = value.extract::<f64>() {
+        Ok(Json::new(v))
+    } else if let Ok(v) = value.extract::<String>() {
+        Ok(Json::new(v))
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| json_from_py(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Json::new(items))
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        Ok(Json::new(json_object_from_dict(py, dict)?))
+    } else {
+        Err(AssetValueError::new_err(format!(
+            "Unsupported metadata value type: {}",
+            value.get_type().name()?
+        )))
+    }
+}
+
+fn json_to_py(py: Python<'_>, value: &Json) -> PyResult<PyObject> {
+    let value: serde_json::Value = value
+        .clone()
+        .try_into_any()
+        .map_err(|e| AssetValueError::new_err(format!("Corrupted metadata value: {e}")))?;
+    match value {
+        serde_json::Value::Null => Ok(py.None()),
+        serde_json::Value::Bool(b) => Ok(b.into_py(py)),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Ok(i.into_py(py))
+            } else if let Some(u) = n.as_u64() {
+                Ok(u.into_py(py))
+            } else if let Some(f) = n.as_f64() {
+                Ok(f.into_py(py))
+            } else {
+                Err(AssetValueError::new_err(format!(
+                    "metadata number {n} could not be converted to a Python value"
+                )))
+            }
+        }
+        serde_json::Value::String(s) => Ok(s.into_py(py)),
+        serde_json::Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(json_to_py(py, &Json::new(item))?)?;
+            }
+            Ok(list.into())
+        }
+        serde_json::Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(key, json_to_py(py, &Json::new(value))?)?;
+            }
+            Ok(dict.into())
+        }
+    }
+}
+
+/// Converts a nested `dict` *value* (reached recursively through
+/// [`json_from_py`]) into a plain JSON object. Unlike [`metadata_from_dict`],
+/// which builds a top-level `Metadata` and so validates every key as a
+/// `Name`, this treats the dict as ordinary JSON: its keys are arbitrary
+/// strings, not `Metadata` keys, so e.g. spaces or `#` in a nested key
+/// round-trip instead of raising `NameParseError`.
+fn json_object_from_dict(
+    py: Python<'_>,
+    dict: &PyDict,
+) -> PyResult<std::collections::BTreeMap<String, Json>> {
+    let mut object = std::collections::BTreeMap::new();
+    for (key, value) in dict.iter() {
+        object.insert(key.extract::<String>()?, json_from_py(py, value)?);
+    }
+    Ok(object)
+}
+
+fn metadata_from_dict(py: Python<'_>, dict: &PyDict) -> PyResult<Metadata> {
+    let mut metadata = Metadata::default();
+    for (key, value) in dict.iter() {
+        let key: Name = key
+            .extract::<&str>()?
+            .parse()
+            .map_err(|e| NameParseError::new_err(format!("Invalid metadata key: {e}")))?;
+        metadata.insert(key, json_from_py(py, value)?);
+    }
+    Ok(metadata)
+}
+
+fn metadata_to_dict<'py>(py: Python<'py>, metadata: &Metadata) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    for (key, value) in metadata.iter() {
+        dict.set_item(key.as_ref(), json_to_py(py, value)?)?;
+    }
+    Ok(dict)
+}
 
 mirror_struct! {
     /// ID of asset definition, e.g. asset#domain
@@ -24,13 +183,11 @@ mirror_struct! {
 #[pymethods]
 impl PyAssetDefinitionId {
     #[new]
-    fn new(name: &str, domain: &str) -> PyResult<Self> {
+    fn new(py: Python<'_>, name: &str, domain: PyObject) -> PyResult<Self> {
         let name = name
             .parse()
-            .map_err(|e| PyValueError::new_err(format!("Invalid AssedDefinitionId name: {e}")))?;
-        let domain = domain
-            .parse()
-            .map_err(|e| PyValueError::new_err(format!("Invalid Domain name: {e}")))?;
+            .map_err(|e| NameParseError::new_err(format!("Invalid AssetDefinitionId name: {e}")))?;
+        let domain = extract_or_parse::<PyDomainId, DomainId>(domain.as_ref(py), "Domain")?;
         Ok(Self(AssetDefinitionId::new(name, domain)))
     }
 
@@ -43,7 +200,7 @@ impl PyAssetDefinitionId {
     fn set_name(&mut self, name: &str) -> PyResult<()> {
         self.0.name = name
             .parse()
-            .map_err(|e| PyValueError::new_err(format!("Invalid AssedDefinitionId name: {e}")))?;
+            .map_err(|e| NameParseError::new_err(format!("Invalid AssetDefinitionId name: {e}")))?;
         Ok(())
     }
 
@@ -53,10 +210,8 @@ impl PyAssetDefinitionId {
     }
 
     #[setter]
-    fn set_domain(&mut self, name: &str) -> PyResult<()> {
-        self.0.domain.name = name
-            .parse()
-            .map_err(|e| PyValueError::new_err(format!("Invalid Domain name: {e}")))?;
+    fn set_domain(&mut self, py: Python<'_>, domain: PyObject) -> PyResult<()> {
+        self.0.domain = extract_or_parse::<PyDomainId, DomainId>(domain.as_ref(py), "Domain")?;
         Ok(())
     }
 }
@@ -118,17 +273,10 @@ impl PyNewAssetDefinition {
         logo: Option<String>,
         metadata: Option<Py<PyDict>>,
     ) -> PyResult<Self> {
-        let id = if let Ok(defn_id) = id.extract::<PyAssetDefinitionId>(py) {
-            defn_id.into()
-        } else if let Ok(str_id) = id.extract::<&str>(py) {
-            str_id
-                .parse()
-                .map_err(|e| PyValueError::new_err(format!("Invalid AssetDefinition id: {e}")))?
-        } else {
-            return Err(PyValueError::new_err(
-                "Invalid AssetDefinition id, expected AssetDefinitionId or a string",
-            ));
-        };
+        let id = extract_or_parse::<PyAssetDefinitionId, AssetDefinitionId>(
+            id.as_ref(py),
+            "AssetDefinitionId",
+        )?;
         let mut new_definition = AssetDefinition::new(id, value_type.into());
         if let Some(mintable) = mintable {
             new_definition.mintable = mintable.into();
@@ -136,11 +284,11 @@ impl PyNewAssetDefinition {
         if let Some(logo) = logo {
             let logo = logo
                 .parse()
-                .map_err(|e| PyValueError::new_err(format!("Invalid IPFS path: {e}")))?;
+                .map_err(|e| IpfsPathError::new_err(format!("Invalid IPFS path: {e}")))?;
             new_definition.logo = Some(logo);
         }
-        if let Some(_metadata) = metadata {
-            todo!()
+        if let Some(metadata) = metadata {
+            new_definition.metadata = metadata_from_dict(py, metadata.as_ref(py))?;
         }
         Ok(Self(new_definition))
     }
@@ -152,13 +300,10 @@ impl PyNewAssetDefinition {
 
     #[setter]
     fn set_id(&mut self, py: Python<'_>, id: PyObject) -> PyResult<()> {
-        if let Ok(s) = id.extract::<&str>(py) {
-            self.0.id = s
-                .parse()
-                .map_err(|e| PyValueError::new_err(format!("Invalid AssetDefinition: {e}")))?;
-        } else {
-            self.0.id = id.extract::<PyAssetDefinitionId>(py)?.into();
-        }
+        self.0.id = extract_or_parse::<PyAssetDefinitionId, AssetDefinitionId>(
+            id.as_ref(py),
+            "AssetDefinitionId",
+        )?;
         Ok(())
     }
 
@@ -192,7 +337,7 @@ impl PyNewAssetDefinition {
         if let Some(path) = new {
             self.0.logo = Some(
                 path.parse()
-                    .map_err(|e| PyValueError::new_err(format!("Malformed IPFS path: {e}")))?,
+                    .map_err(|e| IpfsPathError::new_err(format!("Malformed IPFS path: {e}")))?,
             )
         }
         Ok(())
@@ -209,8 +354,14 @@ mirror_struct! {
 #[pymethods]
 impl PyAssetId {
     #[new]
-    fn new(definition_id: PyAssetDefinitionId, account_id: PyAccountId) -> PyResult<Self> {
-        Ok(Self(AssetId::new(definition_id.into(), account_id.into())))
+    fn new(py: Python<'_>, definition_id: PyObject, account_id: PyObject) -> PyResult<Self> {
+        let definition_id = extract_or_parse::<PyAssetDefinitionId, AssetDefinitionId>(
+            definition_id.as_ref(py),
+            "AssetDefinitionId",
+        )?;
+        let account_id =
+            extract_or_parse::<PyAccountId, AccountId>(account_id.as_ref(py), "AccountId")?;
+        Ok(Self(AssetId::new(definition_id, account_id)))
     }
 
     #[getter]
@@ -219,8 +370,12 @@ impl PyAssetId {
     }
 
     #[setter]
-    fn set_definition_id(&mut self, definition_id: PyAssetDefinitionId) {
-        self.0.definition = definition_id.into()
+    fn set_definition_id(&mut self, py: Python<'_>, definition_id: PyObject) -> PyResult<()> {
+        self.0.definition = extract_or_parse::<PyAssetDefinitionId, AssetDefinitionId>(
+            definition_id.as_ref(py),
+            "AssetDefinitionId",
+        )?;
+        Ok(())
     }
 
     #[getter]
@@ -229,84 +384,239 @@ impl PyAssetId {
     }
 
     #[setter]
-    fn set_account_id(&mut self, account_id: PyAccountId) {
-        self.0.account = account_id.into()
+    fn set_account_id(&mut self, py: Python<'_>, account_id: PyObject) -> PyResult<()> {
+        self.0.account =
+            extract_or_parse::<PyAccountId, AccountId>(account_id.as_ref(py), "AccountId")?;
+        Ok(())
     }
 }
 
-mirror_struct! {
-    /// Asset balance belonging to an account
-    Asset
+/// Asset balance belonging to an account.
+///
+/// Unlike the other mirror types, this one is not produced by
+/// `mirror_struct!` because it also carries the `NumericSpec` of the
+/// asset's registered `AssetDefinition`, which checked arithmetic
+/// validates results against.
+///
+/// The `Asset` the blockchain sends back (e.g. from a query) carries no
+/// `AssetDefinition`/`NumericSpec` of its own — that lives in a separate
+/// entity this module never looks up. So [`PyMirror::mirror`] below, the
+/// path every asset returned from the client takes, can only default to
+/// `NumericSpec::unconstrained()`; arithmetic on such assets is checked
+/// against `u128` overflow only, not the real registered spec. Real
+/// spec-aware validation currently only kicks in for assets explicitly
+/// constructed with `PyAsset.new(..., value_type=...)`.
+#[pyclass(name = "Asset")]
+#[derive(Clone)]
+pub struct PyAsset {
+    asset: Asset,
+    spec: NumericSpec,
+}
+
+impl PyMirror for Asset {
+    type Mirror = PyAsset;
+
+    /// Mirrors a bare `Asset` with `spec: NumericSpec::unconstrained()`,
+    /// since the `AssetDefinition` carrying its real `NumericSpec` isn't
+    /// available here — see the caveat on [`PyAsset`] above.
+    fn mirror(self) -> PyResult<Self::Mirror> {
+        Ok(PyAsset {
+            asset: self,
+            spec: NumericSpec::unconstrained(),
+        })
+    }
+}
+
+fn numeric_spec_of(value_type: &Option<PyAssetType>) -> NumericSpec {
+    match value_type {
+        Some(t) => match t.0 {
+            AssetType::Numeric(spec) => spec,
+            AssetType::Store => NumericSpec::unconstrained(),
+        },
+        None => NumericSpec::unconstrained(),
+    }
+}
+
+/// Converts a Python value passed to `PyAsset.new`/`set_value` into an
+/// `AssetValue`. Exact types are tried before `f64`: `int` and
+/// `decimal.Decimal` preserve their mantissa and scale exactly, and a
+/// `str` is parsed as a decimal literal, so none of them go through the
+/// lossy `f64` path the way e.g. `0.1` would.
+fn extract_asset_value(py: Python<'_>, value: PyObject) -> PyResult<AssetValue> {
+    let any = value.as_ref(py);
+    if let Ok(val) = value.extract::<u32>(py) {
+        Ok(AssetValue::Numeric(Numeric::new(val.into(), 0)))
+    } else if let Ok(val) = value.extract::<u128>(py) {
+        Ok(AssetValue::Numeric(Numeric::new(val, 0)))
+    } else if is_decimal(py, any)? {
+        let decimal = Decimal::from_str(any.str()?.to_str()?)
+            .map_err(|e| AssetValueError::new_err(format!("Invalid Decimal value: {e}")))?;
+        Ok(AssetValue::Numeric(numeric_from_decimal(decimal)?))
+    } else if let Ok(s) = value.extract::<&str>(py) {
+        let decimal = Decimal::from_str(s).map_err(|e| {
+            AssetValueError::new_err(format!("'{s}' is not a valid decimal number: {e}"))
+        })?;
+        Ok(AssetValue::Numeric(numeric_from_decimal(decimal)?))
+    } else if let Ok(val) = value.extract::<f64>(py) {
+        let decimal = Decimal::from_f64(val).ok_or(AssetValueError::new_err(
+            "float could not be converted into decimal number",
+        ))?;
+        Ok(AssetValue::Numeric(numeric_from_decimal(decimal)?))
+    } else if let Ok(dict) = value.extract::<&PyDict>(py) {
+        Ok(AssetValue::Store(metadata_from_dict(py, dict)?))
+    } else {
+        Err(AssetValueError::new_err(format!(
+            "Unrecognised value for asset: {}",
+            value
+        )))
+    }
+}
+
+/// Converts a parsed `Decimal` into a `Numeric`, rejecting negative
+/// values instead of silently wrapping `mantissa()` (a signed `i128`)
+/// into a huge `u128` via `as` casting.
+fn numeric_from_decimal(decimal: Decimal) -> PyResult<Numeric> {
+    if decimal.is_sign_negative() {
+        return Err(AssetValueError::new_err(format!(
+            "asset value must not be negative: {decimal}"
+        )));
+    }
+    Ok(Numeric::new(decimal.mantissa() as u128, decimal.scale()))
+}
+
+/// Checks whether `value` is an instance of Python's `decimal.Decimal`,
+/// without pulling in a dedicated PyO3 decimal binding just for this.
+fn is_decimal(py: Python<'_>, value: &PyAny) -> PyResult<bool> {
+    let decimal_cls = py.import("decimal")?.getattr("Decimal")?;
+    value.is_instance(decimal_cls)
 }
 
 #[pymethods]
 impl PyAsset {
     #[new]
-    fn new(py: Python<'_>, id: PyAssetId, value: PyObject) -> PyResult<Self> {
-        let value = if let Ok(val) = value.extract::<u32>(py) {
-            AssetValue::Numeric(Numeric::new(val.into(), 0))
-        } else if let Ok(val) = value.extract::<u128>(py) {
-            AssetValue::Numeric(Numeric::new(val, 0))
-        } else if let Ok(val) = value.extract::<f64>(py) {
-            let decimal = Decimal::from_f64(val).ok_or(PyValueError::new_err(
-                "float could not be converted into decimal number",
-            ))?;
-            AssetValue::Numeric(Numeric::new(decimal.mantissa() as u128, decimal.scale()))
-        } else {
-            return Err(PyValueError::new_err(format!(
-                "Unrecognised value for asset: {}",
-                value
-            )));
-        };
-
-        Ok(Self(Asset::new(id.0, value)))
+    fn new(
+        py: Python<'_>,
+        id: PyAssetId,
+        value: PyObject,
+        value_type: Option<PyAssetType>,
+    ) -> PyResult<Self> {
+        let value = extract_asset_value(py, value)?;
+        Ok(Self {
+            spec: numeric_spec_of(&value_type),
+            asset: Asset::new(id.0, value),
+        })
     }
 
     #[getter]
     fn get_id(&self) -> PyAssetId {
-        self.0.id.clone().into()
+        self.asset.id.clone().into()
     }
 
     #[setter]
     fn set_id(&mut self, id: PyAssetId) {
-        self.0.id = id.into()
+        self.asset.id = id.into()
     }
 
     #[getter]
     fn get_value(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        match &self.0.value {
+        match &self.asset.value {
             AssetValue::Numeric(n) => {
                 let quantity =
                     Decimal::from_i128_with_scale(n.mantissa() as i128, n.scale()).into_py(py);
                 Ok(quantity.into())
             }
-            AssetValue::Store(_v) => {
-                //let dict = MetadataWrapper(v.clone()).into_py(py)?;
-                //Ok(dict.into())
-                unimplemented!();
+            AssetValue::Store(v) => {
+                let dict = metadata_to_dict(py, v)?;
+                Ok(dict.into())
             }
         }
     }
 
     #[setter]
     fn set_value(&mut self, py: Python<'_>, value: PyObject) -> PyResult<()> {
-        let value = if let Ok(val) = value.extract::<u32>(py) {
-            AssetValue::Numeric(Numeric::new(val.into(), 0))
-        } else if let Ok(val) = value.extract::<u128>(py) {
-            AssetValue::Numeric(Numeric::new(val, 0))
-        } else if let Ok(val) = value.extract::<f64>(py) {
-            let decimal = Decimal::from_f64(val).ok_or(PyValueError::new_err(
-                "float could not be converted into decimal number",
-            ))?;
-            AssetValue::Numeric(Numeric::new(decimal.mantissa() as u128, decimal.scale()))
-        } else {
-            return Err(PyNotImplementedError::new_err(
-                "Metadata Values are currently read-only",
-            ));
-        };
-        self.0.value = value;
+        self.asset.value = extract_asset_value(py, value)?;
         Ok(())
     }
+
+    fn __add__(&self, other: &PyAsset) -> PyResult<PyAsset> {
+        checked_numeric_asset_op(self, other, "add", Numeric::checked_add)
+    }
+
+    fn __sub__(&self, other: &PyAsset) -> PyResult<PyAsset> {
+        checked_numeric_asset_op(self, other, "subtract", Numeric::checked_sub)
+    }
+
+    fn __radd__(&self, py: Python<'_>, other: PyObject) -> PyResult<PyAsset> {
+        if let Ok(0) = other.extract::<i64>(py) {
+            return Ok(self.clone());
+        }
+        other.extract::<PyAsset>(py)?.__add__(self)
+    }
+}
+
+fn numeric_value(asset: &PyAsset) -> PyResult<Numeric> {
+    match asset.asset.value {
+        AssetValue::Numeric(n) => Ok(n),
+        AssetValue::Store(_) => Err(AssetValueError::new_err(
+            "arithmetic is only supported on Numeric assets, not Store",
+        )),
+    }
+}
+
+/// Validates `result` against the asset's registered `NumericSpec`: a
+/// `numeric_fractional` spec fixes the exact scale a value must carry,
+/// while `numeric_unconstrained` imposes no bound beyond the `u128`
+/// overflow the caller already checked via `Numeric`'s checked arithmetic.
+fn check_against_spec(spec: NumericSpec, result: Numeric) -> PyResult<()> {
+    if let Some(fixed_scale) = spec.scale() {
+        if result.scale() != fixed_scale {
+            return Err(AssetValueError::new_err(format!(
+                "asset result has scale {}, but its registered NumericSpec requires scale {fixed_scale}",
+                result.scale()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Shared implementation for `PyAsset.__add__`/`__sub__`: requires both
+/// operands to belong to the same `AssetId` and to have been registered
+/// with the same `NumericSpec`, rescales mantissas to their common
+/// (larger) scale, and rejects the result with an `AssetValueError`
+/// rather than wrapping if it over/underflows `u128` or violates that
+/// `NumericSpec`.
+fn checked_numeric_asset_op(
+    lhs: &PyAsset,
+    rhs: &PyAsset,
+    op_name: &str,
+    op: impl Fn(Numeric, Numeric) -> Option<Numeric>,
+) -> PyResult<PyAsset> {
+    if lhs.asset.id != rhs.asset.id {
+        return Err(AssetValueError::new_err(format!(
+            "cannot {op_name} assets with different AssetIds"
+        )));
+    }
+    if lhs.spec != rhs.spec {
+        return Err(AssetValueError::new_err(format!(
+            "cannot {op_name} assets registered with different NumericSpecs"
+        )));
+    }
+    let lhs_value = numeric_value(lhs)?;
+    let rhs_value = numeric_value(rhs)?;
+    let scale = lhs_value.scale().max(rhs_value.scale());
+    let lhs_value = lhs_value
+        .rescale(scale)
+        .ok_or_else(|| AssetValueError::new_err("asset value overflowed while aligning scale"))?;
+    let rhs_value = rhs_value
+        .rescale(scale)
+        .ok_or_else(|| AssetValueError::new_err("asset value overflowed while aligning scale"))?;
+    let result = op(lhs_value, rhs_value)
+        .ok_or_else(|| AssetValueError::new_err(format!("asset {op_name} overflowed")))?;
+    check_against_spec(lhs.spec, result)?;
+    Ok(PyAsset {
+        asset: Asset::new(lhs.asset.id.clone(), AssetValue::Numeric(result)),
+        spec: lhs.spec,
+    })
 }
 
 #[pyclass(name = "AssetType")]
@@ -347,7 +657,152 @@ mirror_fieldless_enum! {
     Infinitely, Once, Not
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_asset(py: Python<'_>, value: &str, value_type: Option<PyAssetType>) -> PyAsset {
+        make_asset_with_id(py, "rose#wonderland", "alice@wonderland", value, value_type)
+    }
+
+    fn make_asset_with_id(
+        py: Python<'_>,
+        definition_id: &str,
+        account_id: &str,
+        value: &str,
+        value_type: Option<PyAssetType>,
+    ) -> PyAsset {
+        let id = PyAssetId::new(py, definition_id.into_py(py), account_id.into_py(py)).unwrap();
+        PyAsset::new(py, id, value.into_py(py), value_type).unwrap()
+    }
+
+    fn numeric_of(asset: &PyAsset) -> Numeric {
+        match asset.asset.value {
+            AssetValue::Numeric(n) => n,
+            AssetValue::Store(_) => panic!("expected a Numeric asset value"),
+        }
+    }
+
+    #[test]
+    fn add_requires_same_asset_id() {
+        Python::with_gil(|py| {
+            let rose = make_asset_with_id(py, "rose#wonderland", "alice@wonderland", "1", None);
+            let wonder =
+                make_asset_with_id(py, "wonder#wonderland", "alice@wonderland", "1", None);
+            let err = rose.__add__(&wonder).unwrap_err();
+            assert!(err.to_string().contains("different AssetIds"));
+        });
+    }
+
+    #[test]
+    fn add_aligns_differing_scales() {
+        Python::with_gil(|py| {
+            let a = make_asset(py, "1.5", None);
+            let b = make_asset(py, "2.25", None);
+            let result = numeric_of(&a.__add__(&b).unwrap());
+            assert_eq!(result.scale(), 2);
+            assert_eq!(result.mantissa(), 375);
+        });
+    }
+
+    #[test]
+    fn add_rejects_mismatched_numeric_spec() {
+        Python::with_gil(|py| {
+            let a = make_asset(
+                py,
+                "1",
+                Some(PyAssetType::numeric_unconstrained().unwrap()),
+            );
+            let b = make_asset(py, "1", Some(PyAssetType::numeric_fractional(2).unwrap()));
+            let err = a.__add__(&b).unwrap_err();
+            assert!(err.to_string().contains("different NumericSpecs"));
+        });
+    }
+
+    #[test]
+    fn add_rejects_result_violating_registered_scale() {
+        Python::with_gil(|py| {
+            let a = make_asset(py, "1", Some(PyAssetType::numeric_fractional(2).unwrap()));
+            let b = make_asset(py, "2", Some(PyAssetType::numeric_fractional(2).unwrap()));
+            let err = a.__add__(&b).unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("registered NumericSpec requires scale 2"));
+        });
+    }
+
+    #[test]
+    fn add_rejects_overflow() {
+        Python::with_gil(|py| {
+            // u128::MAX exceeds what a decimal string can be parsed into,
+            // so it is passed as a Python int instead.
+            let id = PyAssetId::new(
+                py,
+                "rose#wonderland".into_py(py),
+                "alice@wonderland".into_py(py),
+            )
+            .unwrap();
+            let a = PyAsset::new(py, id, u128::MAX.into_py(py), None).unwrap();
+            let b = make_asset(py, "1", None);
+            let err = a.__add__(&b).unwrap_err();
+            assert!(err.to_string().contains("overflowed"));
+        });
+    }
+
+    #[test]
+    fn radd_with_int_zero_is_identity() {
+        Python::with_gil(|py| {
+            let a = make_asset(py, "5", None);
+            let result = a.__radd__(py, 0i64.into_py(py)).unwrap();
+            assert_eq!(numeric_of(&result).mantissa(), 5);
+        });
+    }
+
+    #[test]
+    fn radd_with_asset_delegates_to_add() {
+        Python::with_gil(|py| {
+            let a = make_asset(py, "5", None);
+            let b = make_asset(py, "3", None);
+            let result = a.__radd__(py, b.into_py(py)).unwrap();
+            assert_eq!(numeric_of(&result).mantissa(), 8);
+        });
+    }
+
+    #[test]
+    fn extract_asset_value_rejects_negative_decimal() {
+        Python::with_gil(|py| {
+            let decimal_cls = py.import("decimal").unwrap().getattr("Decimal").unwrap();
+            let value = decimal_cls.call1(("-5.2",)).unwrap().into_py(py);
+            let err = extract_asset_value(py, value).unwrap_err();
+            assert!(err.to_string().contains("must not be negative"));
+        });
+    }
+
+    #[test]
+    fn extract_asset_value_rejects_negative_string() {
+        Python::with_gil(|py| {
+            let err = extract_asset_value(py, "-5.2".into_py(py)).unwrap_err();
+            assert!(err.to_string().contains("must not be negative"));
+        });
+    }
+
+    #[test]
+    fn extract_asset_value_preserves_decimal_precision() {
+        Python::with_gil(|py| {
+            let value = extract_asset_value(py, "0.10".into_py(py)).unwrap();
+            match value {
+                AssetValue::Numeric(n) => {
+                    assert_eq!(n.mantissa(), 10);
+                    assert_eq!(n.scale(), 2);
+                }
+                AssetValue::Store(_) => panic!("expected a Numeric asset value"),
+            }
+        });
+    }
+}
+
 pub fn register_items(_py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyMetadata>()?;
     module.add_class::<PyAssetDefinitionId>()?;
     module.add_class::<PyAssetDefinition>()?;
     module.add_class::<PyNewAssetDefinition>()?;
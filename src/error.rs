@@ -0,0 +1,37 @@
+//! Typed Python exceptions raised by the data model and instruction bindings,
+//! in place of string-matched `ValueError`s.
+
+use pyo3::{create_exception, exceptions::PyValueError, prelude::*};
+
+create_exception!(
+    iroha2,
+    IrohaError,
+    PyValueError,
+    "Base class for all errors raised by the iroha2 SDK."
+);
+create_exception!(
+    iroha2,
+    NameParseError,
+    IrohaError,
+    "Raised when a `Name`, or an id built from one, fails to parse."
+);
+create_exception!(
+    iroha2,
+    IpfsPathError,
+    IrohaError,
+    "Raised when a logo path is not a valid IPFS path."
+);
+create_exception!(
+    iroha2,
+    AssetValueError,
+    IrohaError,
+    "Raised when an asset value cannot be recognised or converted."
+);
+
+pub fn register_items(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add("IrohaError", py.get_type::<IrohaError>())?;
+    module.add("NameParseError", py.get_type::<NameParseError>())?;
+    module.add("IpfsPathError", py.get_type::<IpfsPathError>())?;
+    module.add("AssetValueError", py.get_type::<AssetValueError>())?;
+    Ok(())
+}
@@ -2,12 +2,14 @@ use pyo3::prelude::*;
 
 mod client;
 mod data_model;
+mod error;
 mod isi;
 
 /// The iroha2 python SDK module, implemented in Rust.
 #[pymodule]
 #[pyo3(name = "iroha2")]
 fn iroha2_python(py: Python, m: &PyModule) -> PyResult<()> {
+    error::register_items(py, m)?;
     client::register_items(py, m)?;
     data_model::register_items(py, m)?;
     isi::register_items(py, m)?;